@@ -1,4 +1,5 @@
-use clap::{Parser, Subcommand};
+use crate::config::DEFAULT_SEPARATOR;
+use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -12,20 +13,43 @@ pub struct Cli {
     pub command: Commands,
 }
 
+/// Shared `--config`/`--set` arguments for subcommands that load configuration.
+#[derive(Args, Debug)]
+pub struct ConfigArgs {
+    /// Configuration source(s) to load, each one of:
+    /// a file path (auto-detects .env, .json, .toml, or .yaml/.yml),
+    /// an inline JSON object, or comma-separated `key=value` pairs.
+    /// Values in later sources override earlier ones.
+    #[arg(short, long, value_name = "FILE|JSON|KEY=VALUE,...")]
+    pub config: Vec<PathBuf>,
+
+    /// Inline `KEY=VALUE` override, repeatable.
+    /// Takes priority over every loaded config file.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub set: Vec<String>,
+
+    /// Separator used to join nested keys when flattening, e.g. `database.url`
+    /// becomes `DATABASE_URL` with the default separator.
+    #[arg(long, default_value = DEFAULT_SEPARATOR)]
+    pub separator: String,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Loads configuration and runs a command
     Run {
-        /// Configuration file(s) to load.
-        /// Tries to auto-detect .env, .json, or .toml.
-        /// Values in later files override earlier ones.
-        #[arg(short, long, value_name = "FILE")]
-        config: Vec<PathBuf>,
+        #[command(flatten)]
+        config_args: ConfigArgs,
 
         /// The command to execute
         #[arg(required = true, trailing_var_arg = true)]
         command: Vec<String>,
     },
+    /// Prints the fully merged configuration along with each key's source
+    Dump {
+        #[command(flatten)]
+        config_args: ConfigArgs,
+    },
     // You can add stubs for future features:
     // Encrypt {
     //     #[arg(short, long, value_name = "FILE")]
@@ -8,22 +8,54 @@ pub type ConfigMap = HashMap<String, String>;
 
 /// Loads configuration from a given file path.
 ///
-/// Auto-detects the format (.env, .json, .toml) based on the extension.
-pub fn load_config_file(path: &Path) -> Result<ConfigMap, ConfixError> {
+/// Auto-detects the format (.env, .json, .toml, .yaml/.yml) based on the
+/// extension. Nested keys are joined with `sep`.
+pub fn load_config_file(path: &Path, sep: &str) -> Result<ConfigMap, ConfixError> {
     if !path.exists() {
         return Err(ConfixError::FileNotFound(path.to_path_buf()));
     }
 
     match path.extension().and_then(|s| s.to_str()) {
         Some("env") => load_dotenv(path),
-        Some("json") => load_json(path),
-        Some("toml") => load_toml(path),
+        Some("json") => load_json(path, sep),
+        Some("toml") => load_toml(path, sep),
+        Some("yaml") | Some("yml") => load_yaml(path, sep),
         // Handle .env file with no extension
         _ if path.file_name().and_then(|s| s.to_str()) == Some(".env") => load_dotenv(path),
         _ => Err(ConfixError::UnsupportedFormat(path.to_path_buf())),
     }
 }
 
+/// Resolves a `--config` argument that isn't a path to an existing file.
+///
+/// Tries the raw text first as an inline JSON object, then as
+/// comma-separated `key=value` pairs, and errors only if both fail. Nested
+/// keys in the JSON case are joined with `sep`.
+pub fn parse_inline_config(arg: &str, sep: &str) -> Result<ConfigMap, ConfixError> {
+    if let Ok(value @ serde_json::Value::Object(_)) = serde_json::from_str(arg) {
+        let mut config = ConfigMap::new();
+        flatten_json(&value, &mut Vec::new(), sep, &mut config)?;
+        return Ok(config);
+    }
+
+    if let Some(config) = parse_comma_pairs(arg) {
+        return Ok(config);
+    }
+
+    Err(ConfixError::AmbiguousConfigArg(arg.to_string()))
+}
+
+/// Parses comma-separated `key=value` pairs, e.g. `PORT=8080,DEBUG=1`.
+/// Returns `None` if any pair is missing its `=` separator.
+fn parse_comma_pairs(arg: &str) -> Option<ConfigMap> {
+    let mut config = ConfigMap::new();
+    for pair in arg.split(',') {
+        let (key, value) = pair.split_once('=')?;
+        config.insert(key.to_string(), value.to_string());
+    }
+    Some(config)
+}
+
 /// Loads a .env file.
 fn load_dotenv(path: &Path) -> Result<ConfigMap, ConfixError> {
     // Use dotenvy::from_path_iter to read without modifying the environment
@@ -36,22 +68,220 @@ fn load_dotenv(path: &Path) -> Result<ConfigMap, ConfixError> {
     Ok(config)
 }
 
+/// Default separator used to join nested keys, e.g. `database.url` -> `DATABASE_URL`.
+/// Overridable via `--separator`.
+pub const DEFAULT_SEPARATOR: &str = "_";
+
+/// Joins a flattened key path into its final env-style name.
+///
+/// A lone top-level key keeps its original case, matching the untouched
+/// keys `load_dotenv` and a flat JSON/TOML/YAML table produce. A key
+/// reached through nesting is fully upper-cased and joined with `sep` to
+/// match environment-variable conventions.
+fn flatten_key(path: &[String], sep: &str) -> String {
+    match path {
+        [single] => single.clone(),
+        segments => segments
+            .iter()
+            .map(|s| s.to_uppercase())
+            .collect::<Vec<_>>()
+            .join(sep),
+    }
+}
+
 /// Loads a .json file.
-/// Expects a flat JSON object with string values.
-fn load_json(path: &Path) -> Result<ConfigMap, ConfixError> {
+///
+/// Nested objects are flattened into env-style keys, e.g.
+/// `{"database": {"url": "..."}}` becomes `DATABASE_URL` (with the default
+/// `sep`).
+fn load_json(path: &Path, sep: &str) -> Result<ConfigMap, ConfixError> {
     let content = fs::read_to_string(path)?;
-    let config: ConfigMap = serde_json::from_str(&content)?;
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+    let mut config = ConfigMap::new();
+    flatten_json(&value, &mut Vec::new(), sep, &mut config)?;
     Ok(config)
 }
 
+/// Recursively flattens a JSON value into `out`, joining nested keys with `sep`.
+fn flatten_json(
+    value: &serde_json::Value,
+    path: &mut Vec<String>,
+    sep: &str,
+    out: &mut ConfigMap,
+) -> Result<(), ConfixError> {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, val) in map {
+                path.push(key.clone());
+                flatten_json(val, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        serde_json::Value::Array(items) => {
+            if items.iter().any(serde_json::Value::is_object) {
+                return Err(ConfixError::UnsupportedArrayOfTables(path.join(sep)));
+            }
+            for (i, item) in items.iter().enumerate() {
+                path.push(i.to_string());
+                flatten_json(item, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        serde_json::Value::Null => {
+            out.insert(flatten_key(path, sep), String::new());
+            Ok(())
+        }
+        serde_json::Value::String(s) => {
+            out.insert(flatten_key(path, sep), s.clone());
+            Ok(())
+        }
+        serde_json::Value::Number(n) => {
+            out.insert(flatten_key(path, sep), n.to_string());
+            Ok(())
+        }
+        serde_json::Value::Bool(b) => {
+            out.insert(flatten_key(path, sep), b.to_string());
+            Ok(())
+        }
+    }
+}
+
 /// Loads a .toml file.
-/// Expects a flat TOML table with string values.
-fn load_toml(path: &Path) -> Result<ConfigMap, ConfixError> {
+///
+/// Nested tables are flattened into env-style keys, e.g.
+/// `[database]\nurl = "..."` becomes `DATABASE_URL` (with the default
+/// `sep`).
+fn load_toml(path: &Path, sep: &str) -> Result<ConfigMap, ConfixError> {
+    let content = fs::read_to_string(path)?;
+    let value: toml::Value = toml::from_str(&content)?;
+    let mut config = ConfigMap::new();
+    flatten_toml(&value, &mut Vec::new(), sep, &mut config)?;
+    Ok(config)
+}
+
+/// Recursively flattens a TOML value into `out`, joining nested keys with `sep`.
+fn flatten_toml(
+    value: &toml::Value,
+    path: &mut Vec<String>,
+    sep: &str,
+    out: &mut ConfigMap,
+) -> Result<(), ConfixError> {
+    match value {
+        toml::Value::Table(map) => {
+            for (key, val) in map {
+                path.push(key.clone());
+                flatten_toml(val, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        toml::Value::Array(items) => {
+            if items.iter().any(toml::Value::is_table) {
+                return Err(ConfixError::UnsupportedArrayOfTables(path.join(sep)));
+            }
+            for (i, item) in items.iter().enumerate() {
+                path.push(i.to_string());
+                flatten_toml(item, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        toml::Value::String(s) => {
+            out.insert(flatten_key(path, sep), s.clone());
+            Ok(())
+        }
+        toml::Value::Integer(i) => {
+            out.insert(flatten_key(path, sep), i.to_string());
+            Ok(())
+        }
+        toml::Value::Float(f) => {
+            out.insert(flatten_key(path, sep), f.to_string());
+            Ok(())
+        }
+        toml::Value::Boolean(b) => {
+            out.insert(flatten_key(path, sep), b.to_string());
+            Ok(())
+        }
+        toml::Value::Datetime(dt) => {
+            out.insert(flatten_key(path, sep), dt.to_string());
+            Ok(())
+        }
+    }
+}
+
+/// Loads a .yaml/.yml file.
+///
+/// Nested mappings are flattened into env-style keys, e.g.
+/// `database:\n  url: ...` becomes `DATABASE_URL` (with the default `sep`).
+fn load_yaml(path: &Path, sep: &str) -> Result<ConfigMap, ConfixError> {
     let content = fs::read_to_string(path)?;
-    let config: ConfigMap = toml::from_str(&content)?;
+    let value: serde_yaml::Value = serde_yaml::from_str(&content)?;
+    let mut config = ConfigMap::new();
+    flatten_yaml(&value, &mut Vec::new(), sep, &mut config)?;
     Ok(config)
 }
 
+/// Recursively flattens a YAML value into `out`, joining nested keys with `sep`.
+fn flatten_yaml(
+    value: &serde_yaml::Value,
+    path: &mut Vec<String>,
+    sep: &str,
+    out: &mut ConfigMap,
+) -> Result<(), ConfixError> {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (key, val) in map {
+                path.push(yaml_key_to_string(key));
+                flatten_yaml(val, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        serde_yaml::Value::Sequence(items) => {
+            if items.iter().any(serde_yaml::Value::is_mapping) {
+                return Err(ConfixError::UnsupportedArrayOfTables(path.join(sep)));
+            }
+            for (i, item) in items.iter().enumerate() {
+                path.push(i.to_string());
+                flatten_yaml(item, path, sep, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        serde_yaml::Value::Null => {
+            out.insert(flatten_key(path, sep), String::new());
+            Ok(())
+        }
+        serde_yaml::Value::Bool(b) => {
+            out.insert(flatten_key(path, sep), b.to_string());
+            Ok(())
+        }
+        serde_yaml::Value::Number(n) => {
+            out.insert(flatten_key(path, sep), n.to_string());
+            Ok(())
+        }
+        serde_yaml::Value::String(s) => {
+            out.insert(flatten_key(path, sep), s.clone());
+            Ok(())
+        }
+        serde_yaml::Value::Tagged(tagged) => flatten_yaml(&tagged.value, path, sep, out),
+    }
+}
+
+/// Converts a YAML mapping key to a string, falling back to its YAML
+/// representation for non-string keys.
+fn yaml_key_to_string(key: &serde_yaml::Value) -> String {
+    match key.as_str() {
+        Some(s) => s.to_string(),
+        None => serde_yaml::to_string(key)
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,7 +293,7 @@ mod tests {
         let mut file = NamedTempFile::new().unwrap();
         writeln!(file, "DATABASE_URL=postgres://...\nAPI_KEY=12345").unwrap();
 
-        let config = load_config_file(file.path()).unwrap();
+        let config = load_config_file(file.path(), DEFAULT_SEPARATOR).unwrap();
         assert_eq!(config.get("DATABASE_URL").unwrap(), "postgres://...");
         assert_eq!(config.get("API_KEY").unwrap(), "12345");
     }
@@ -79,7 +309,7 @@ mod tests {
         )
         .unwrap();
 
-        let config = load_config_file(&json_file).unwrap();
+        let config = load_config_file(&json_file, DEFAULT_SEPARATOR).unwrap();
         assert_eq!(config.get("DATABASE_URL").unwrap(), "json://...");
         assert_eq!(config.get("API_KEY").unwrap(), "abc");
 
@@ -97,7 +327,7 @@ mod tests {
         )
         .unwrap();
 
-        let config = load_config_file(&toml_file).unwrap();
+        let config = load_config_file(&toml_file, DEFAULT_SEPARATOR).unwrap();
         assert_eq!(config.get("DATABASE_URL").unwrap(), "toml://...");
         assert_eq!(config.get("API_KEY").unwrap(), "xyz");
 
@@ -121,7 +351,7 @@ mod tests {
         fs::write(path, "SECRET=from-dotenv").unwrap();
 
         // Run the test
-        let config = load_config_file(path).unwrap();
+        let config = load_config_file(path, DEFAULT_SEPARATOR).unwrap();
         assert_eq!(config.get("SECRET").unwrap(), "from-dotenv");
 
         // _guard will automatically delete the file
@@ -130,19 +360,160 @@ mod tests {
     #[test]
     fn test_file_not_found() {
         let path = Path::new("nonexistent.file");
-        let result = load_config_file(path);
+        let result = load_config_file(path, DEFAULT_SEPARATOR);
         assert!(matches!(result, Err(ConfixError::FileNotFound(_))));
     }
 
+    #[test]
+    fn test_load_json_nested_table() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let json_file = file.into_temp_path().with_extension("json");
+        fs::write(
+            &json_file,
+            r#"{"database": {"url": "postgres://...", "port": 5432}}"#,
+        )
+        .unwrap();
+
+        let config = load_config_file(&json_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("DATABASE_URL").unwrap(), "postgres://...");
+        assert_eq!(config.get("DATABASE_PORT").unwrap(), "5432");
+
+        fs::remove_file(&json_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_json_nested_table_custom_separator() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let json_file = file.into_temp_path().with_extension("json");
+        fs::write(&json_file, r#"{"database": {"url": "postgres://..."}}"#).unwrap();
+
+        let config = load_config_file(&json_file, ".").unwrap();
+        assert_eq!(config.get("DATABASE.URL").unwrap(), "postgres://...");
+
+        fs::remove_file(&json_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_json_flat_table_preserves_key_case() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let json_file = file.into_temp_path().with_extension("json");
+        fs::write(&json_file, r#"{"database_url": "json://..."}"#).unwrap();
+
+        let config = load_config_file(&json_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("database_url").unwrap(), "json://...");
+        assert!(!config.contains_key("DATABASE_URL"));
+
+        fs::remove_file(&json_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_toml_nested_table() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let toml_file = file.into_temp_path().with_extension("toml");
+        fs::write(&toml_file, "[database]\nurl = \"toml://...\"\nport = 5432").unwrap();
+
+        let config = load_config_file(&toml_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("DATABASE_URL").unwrap(), "toml://...");
+        assert_eq!(config.get("DATABASE_PORT").unwrap(), "5432");
+
+        fs::remove_file(&toml_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_toml_flat_table_preserves_key_case() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let toml_file = file.into_temp_path().with_extension("toml");
+        fs::write(&toml_file, "database_url = \"toml://...\"").unwrap();
+
+        let config = load_config_file(&toml_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("database_url").unwrap(), "toml://...");
+        assert!(!config.contains_key("DATABASE_URL"));
+
+        fs::remove_file(&toml_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_yaml_file() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let yaml_file = file.into_temp_path().with_extension("yaml");
+        fs::write(&yaml_file, "DATABASE_URL: yaml://...\nAPI_KEY: abc").unwrap();
+
+        let config = load_config_file(&yaml_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("DATABASE_URL").unwrap(), "yaml://...");
+        assert_eq!(config.get("API_KEY").unwrap(), "abc");
+
+        fs::remove_file(&yaml_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_yaml_nested_mapping() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let yaml_file = file.into_temp_path().with_extension("yml");
+        fs::write(&yaml_file, "database:\n  url: yaml://...\n  port: 5432").unwrap();
+
+        let config = load_config_file(&yaml_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("DATABASE_URL").unwrap(), "yaml://...");
+        assert_eq!(config.get("DATABASE_PORT").unwrap(), "5432");
+
+        fs::remove_file(&yaml_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_yaml_flat_mapping_preserves_key_case() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let yaml_file = file.into_temp_path().with_extension("yaml");
+        fs::write(&yaml_file, "database_url: yaml://...").unwrap();
+
+        let config = load_config_file(&yaml_file, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("database_url").unwrap(), "yaml://...");
+        assert!(!config.contains_key("DATABASE_URL"));
+
+        fs::remove_file(&yaml_file).unwrap();
+    }
+
+    #[test]
+    fn test_load_json_array_of_tables_errors() {
+        let file = NamedTempFile::new_in(".").unwrap();
+        let json_file = file.into_temp_path().with_extension("json");
+        fs::write(&json_file, r#"{"servers": [{"host": "a"}, {"host": "b"}]}"#).unwrap();
+
+        let result = load_config_file(&json_file, DEFAULT_SEPARATOR);
+        assert!(matches!(
+            result,
+            Err(ConfixError::UnsupportedArrayOfTables(_))
+        ));
+
+        fs::remove_file(&json_file).unwrap();
+    }
+
     #[test]
     fn test_unsupported_format() {
         let file = NamedTempFile::new_in(".").unwrap();
         let path = file.into_temp_path().with_extension("txt");
         fs::write(&path, "hello=world").unwrap();
 
-        let result = load_config_file(&path);
+        let result = load_config_file(&path, DEFAULT_SEPARATOR);
         assert!(matches!(result, Err(ConfixError::UnsupportedFormat(_))));
 
         fs::remove_file(&path).unwrap();
     }
+
+    #[test]
+    fn test_parse_inline_config_json() {
+        let config = parse_inline_config(r#"{"PORT": "8080"}"#, DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("PORT").unwrap(), "8080");
+    }
+
+    #[test]
+    fn test_parse_inline_config_comma_pairs() {
+        let config = parse_inline_config("PORT=8080,DEBUG=1", DEFAULT_SEPARATOR).unwrap();
+        assert_eq!(config.get("PORT").unwrap(), "8080");
+        assert_eq!(config.get("DEBUG").unwrap(), "1");
+    }
+
+    #[test]
+    fn test_parse_inline_config_ambiguous() {
+        let result = parse_inline_config("not a valid config at all", DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::AmbiguousConfigArg(_))));
+    }
 }
@@ -15,6 +15,9 @@ pub enum ConfixError {
     #[error("TOML parsing error: {0}")]
     Toml(#[from] toml::de::Error),
 
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     #[error("Command execution failed: {0}")]
     CommandFailed(String),
 
@@ -23,4 +26,24 @@ pub enum ConfixError {
 
     #[error("Configuration file not found: {0}")]
     FileNotFound(PathBuf),
+
+    #[error("Invalid --set override '{0}', expected KEY=VALUE")]
+    InvalidOverride(String),
+
+    #[error("Cannot flatten array of tables at key '{0}'; index the array explicitly instead")]
+    UnsupportedArrayOfTables(String),
+
+    #[error("Interpolation cycle detected involving key '{0}'")]
+    InterpolationCycle(String),
+
+    #[error("Unterminated '${{' reference (missing closing '}}')")]
+    UnterminatedInterpolation,
+
+    #[error("Could not resolve reference '{0}' in any config key or the environment")]
+    UnresolvedReference(String),
+
+    #[error(
+        "Could not interpret --config argument '{0}' as a file path, inline JSON object, or comma-separated key=value pairs"
+    )]
+    AmbiguousConfigArg(String),
 }
@@ -4,6 +4,7 @@ use std::process;
 mod cli;
 mod config;
 mod error;
+mod provenance;
 mod runner;
 
 use cli::{Cli, Commands};
@@ -13,7 +14,17 @@ fn main() {
 
     // Run the main logic and store the result
     let result = match cli.command {
-        Commands::Run { config, command } => runner::run_command(&config, &command), // Handle future commands here
+        Commands::Run { config_args, command } => runner::run_command(
+            &config_args.config,
+            &config_args.set,
+            &command,
+            &config_args.separator,
+        ),
+        Commands::Dump { config_args } => {
+            runner::dump_command(&config_args.config, &config_args.set, &config_args.separator)
+                .map(|_| 0)
+        }
+        // Handle future commands here
                                                                                      // Commands::Encrypt { .. } => {
                                                                                      //     println!("Encryption feature not yet implemented.");
                                                                                      //     Ok(0)
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Where a single configuration value was ultimately set from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Loaded from a config file at this path.
+    File(PathBuf),
+    /// Supplied via a `--config` argument that wasn't a file path (inline
+    /// JSON or comma-separated `key=value` pairs).
+    Inline(String),
+    /// Supplied via a `--set KEY=VALUE` command line flag.
+    Override,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::File(path) => write!(f, "{}", path.display()),
+            ConfigSource::Inline(raw) => write!(f, "--config {raw}"),
+            ConfigSource::Override => write!(f, "--set"),
+        }
+    }
+}
+
+/// A config value together with where it came from, and, if it replaced an
+/// earlier value, where that earlier value came from.
+#[derive(Debug, Clone)]
+pub struct AnnotatedValue {
+    pub value: String,
+    pub source: ConfigSource,
+    pub overrides: Option<ConfigSource>,
+}
+
+/// A config map that remembers the provenance of each key's final value.
+pub type AnnotatedConfigMap = HashMap<String, AnnotatedValue>;
+
+/// Inserts `value` for `key`, recording `source` and, if a value was already
+/// present, noting that the new value overrides its source.
+pub fn set_annotated(map: &mut AnnotatedConfigMap, key: String, value: String, source: ConfigSource) {
+    let overrides = map.get(&key).map(|existing| existing.source.clone());
+    map.insert(
+        key,
+        AnnotatedValue {
+            value,
+            source,
+            overrides,
+        },
+    );
+}
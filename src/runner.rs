@@ -1,33 +1,218 @@
-use crate::config::{ConfigMap, load_config_file};
+use crate::config::{ConfigMap, load_config_file, parse_inline_config};
 use crate::error::ConfixError;
+use crate::provenance::{AnnotatedConfigMap, ConfigSource, set_annotated};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 
-/// Loads all provided config files and merges them.
-/// Later files override earlier ones.
-fn merge_configs(paths: &[PathBuf]) -> Result<ConfigMap, ConfixError> {
-    let mut merged_config = ConfigMap::new();
+/// Loads all provided `--config` arguments and merges them, recording the
+/// source of each key so later arguments' values (and sources) win.
+///
+/// Each argument that names an existing file is loaded via
+/// [`load_config_file`]; an argument that merely *looks* like a file path
+/// (a recognized config extension, or a path separator with no `=` and no
+/// leading `{`)
+/// but doesn't exist reports [`ConfixError::FileNotFound`] rather than
+/// being misread as inline JSON or `key=value` pairs. Anything else is
+/// resolved via [`parse_inline_config`]. Nested keys are joined with `sep`.
+fn merge_configs(paths: &[PathBuf], sep: &str) -> Result<AnnotatedConfigMap, ConfixError> {
+    let mut merged_config = AnnotatedConfigMap::new();
     for path in paths {
-        let config = load_config_file(path)?;
-        merged_config.extend(config);
+        if path.exists() {
+            let config = load_config_file(path, sep)?;
+            for (key, value) in config {
+                set_annotated(&mut merged_config, key, value, ConfigSource::File(path.clone()));
+            }
+        } else if looks_like_file_path(path) {
+            return Err(ConfixError::FileNotFound(path.clone()));
+        } else {
+            let raw = path.to_string_lossy().into_owned();
+            let config = parse_inline_config(&raw, sep)?;
+            for (key, value) in config {
+                set_annotated(&mut merged_config, key, value, ConfigSource::Inline(raw.clone()));
+            }
+        }
     }
     Ok(merged_config)
 }
 
+/// Heuristic for whether a nonexistent `--config` argument was likely
+/// intended as a file path (so a mistyped path reports `FileNotFound`
+/// instead of the far less helpful `AmbiguousConfigArg`).
+///
+/// True if the argument has a recognized config extension, or contains a
+/// path separator and isn't shaped like inline JSON or a `key=value` pair.
+fn looks_like_file_path(path: &std::path::Path) -> bool {
+    let has_known_extension = matches!(
+        path.extension().and_then(|s| s.to_str()),
+        Some("env") | Some("json") | Some("toml") | Some("yaml") | Some("yml")
+    );
+    if has_known_extension {
+        return true;
+    }
+
+    let raw = path.to_string_lossy();
+    let has_path_separator = raw.contains('/') || raw.contains('\\');
+    let looks_like_inline = raw.contains('=') || raw.trim_start().starts_with('{');
+    has_path_separator && !looks_like_inline
+}
+
+/// Parses `--set` arguments of the form `KEY=VALUE` into a `ConfigMap`.
+fn parse_overrides(overrides: &[String]) -> Result<ConfigMap, ConfixError> {
+    let mut parsed = ConfigMap::new();
+    for entry in overrides {
+        let (key, value) = entry
+            .split_once('=')
+            .ok_or_else(|| ConfixError::InvalidOverride(entry.clone()))?;
+        parsed.insert(key.to_string(), value.to_string());
+    }
+    Ok(parsed)
+}
+
+/// Applies CLI `--set` overrides on top of a merged config, recording that
+/// they take priority over every loaded file.
+fn apply_overrides(config: &mut AnnotatedConfigMap, overrides: ConfigMap) {
+    for (key, value) in overrides {
+        set_annotated(config, key, value, ConfigSource::Override);
+    }
+}
+
+/// Loads and merges configuration from files and `--set` overrides, then
+/// resolves `${VAR}` interpolation across the merged values.
+fn load_merged_config(
+    config_paths: &[PathBuf],
+    overrides: &[String],
+    sep: &str,
+) -> Result<AnnotatedConfigMap, ConfixError> {
+    let mut config = merge_configs(config_paths, sep)?;
+    let overrides = parse_overrides(overrides)?;
+    apply_overrides(&mut config, overrides);
+    interpolate(&mut config)?;
+    Ok(config)
+}
+
+/// Resolves `${KEY}` references inside config values in place, substituting
+/// another config key's value or, if no such key exists, the process
+/// environment variable of the same name. `$${...}` escapes to a literal
+/// `${...}` without substitution.
+fn interpolate(config: &mut AnnotatedConfigMap) -> Result<(), ConfixError> {
+    let mut resolved = HashMap::new();
+    let mut in_progress = HashSet::new();
+
+    let keys: Vec<String> = config.keys().cloned().collect();
+    for key in &keys {
+        resolve_key(key, config, &mut resolved, &mut in_progress)?;
+    }
+
+    for (key, value) in resolved {
+        if let Some(annotated) = config.get_mut(&key) {
+            annotated.value = value;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves the fully-interpolated value of `key`, memoizing into `resolved`
+/// and tracking `in_progress` keys to detect reference cycles.
+fn resolve_key(
+    key: &str,
+    config: &AnnotatedConfigMap,
+    resolved: &mut HashMap<String, String>,
+    in_progress: &mut HashSet<String>,
+) -> Result<String, ConfixError> {
+    if let Some(value) = resolved.get(key) {
+        return Ok(value.clone());
+    }
+    if !in_progress.insert(key.to_string()) {
+        return Err(ConfixError::InterpolationCycle(key.to_string()));
+    }
+
+    let raw = config[key].value.clone();
+    let value = substitute(&raw, config, resolved, in_progress)?;
+
+    in_progress.remove(key);
+    resolved.insert(key.to_string(), value.clone());
+    Ok(value)
+}
+
+/// Expands `${KEY}` and `$${KEY}` references found in `raw`.
+fn substitute(
+    raw: &str,
+    config: &AnnotatedConfigMap,
+    resolved: &mut HashMap<String, String>,
+    in_progress: &mut HashSet<String>,
+) -> Result<String, ConfixError> {
+    let chars: Vec<char> = raw.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i..].starts_with(&['$', '$', '{']) {
+            let end = find_closing_brace(&chars, i + 3)?;
+            out.push_str("${");
+            out.extend(&chars[i + 3..end]);
+            out.push('}');
+            i = end + 1;
+        } else if chars[i..].starts_with(&['$', '{']) {
+            let end = find_closing_brace(&chars, i + 2)?;
+            let ref_key: String = chars[i + 2..end].iter().collect();
+            out.push_str(&resolve_reference(&ref_key, config, resolved, in_progress)?);
+            i = end + 1;
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+/// Resolves a single `${KEY}` reference against the merged config, falling
+/// back to the process environment.
+fn resolve_reference(
+    ref_key: &str,
+    config: &AnnotatedConfigMap,
+    resolved: &mut HashMap<String, String>,
+    in_progress: &mut HashSet<String>,
+) -> Result<String, ConfixError> {
+    if config.contains_key(ref_key) {
+        return resolve_key(ref_key, config, resolved, in_progress);
+    }
+    std::env::var(ref_key).map_err(|_| ConfixError::UnresolvedReference(ref_key.to_string()))
+}
+
+/// Finds the index of the `}` closing a reference that started at `start`.
+fn find_closing_brace(chars: &[char], start: usize) -> Result<usize, ConfixError> {
+    chars[start..]
+        .iter()
+        .position(|&c| c == '}')
+        .map(|pos| start + pos)
+        .ok_or(ConfixError::UnterminatedInterpolation)
+}
+
 /// Executes a command with the given configuration as environment variables.
-pub fn run_command(config_paths: &[PathBuf], cmd_args: &[String]) -> Result<i32, ConfixError> {
-    // 1. Load and merge configurations
-    let config = merge_configs(config_paths)?;
+pub fn run_command(
+    config_paths: &[PathBuf],
+    overrides: &[String],
+    cmd_args: &[String],
+    sep: &str,
+) -> Result<i32, ConfixError> {
+    // 1. Load and merge configuration from files and CLI overrides
+    let config = load_merged_config(config_paths, overrides, sep)?;
 
     // 2. Separate command and its arguments
     let (command, args) = cmd_args
         .split_first()
         .ok_or_else(|| ConfixError::CommandFailed("No command provided.".to_string()))?;
 
-    // 3. Build the command
+    // 3. Project down to plain key/value pairs for injection
+    let env: HashMap<&str, &str> = config
+        .iter()
+        .map(|(key, annotated)| (key.as_str(), annotated.value.as_str()))
+        .collect();
+
+    // 4. Build the command
     let mut child = Command::new(command)
         .args(args)
-        .envs(&config) // Inject the merged config as env vars
+        .envs(&env) // Inject the merged config as env vars
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -36,7 +221,7 @@ pub fn run_command(config_paths: &[PathBuf], cmd_args: &[String]) -> Result<i32,
             ConfixError::CommandFailed(format!("Failed to spawn command '{}': {}", command, e))
         })?;
 
-    // 4. Wait for the command to finish
+    // 5. Wait for the command to finish
     let status = child.wait().map_err(|e| {
         ConfixError::CommandFailed(format!("Command '{}' failed to run: {}", command, e))
     })?;
@@ -45,9 +230,34 @@ pub fn run_command(config_paths: &[PathBuf], cmd_args: &[String]) -> Result<i32,
     Ok(status.code().unwrap_or(0))
 }
 
+/// Loads and merges configuration, then prints each key's value along with
+/// the source it ultimately came from.
+pub fn dump_command(
+    config_paths: &[PathBuf],
+    overrides: &[String],
+    sep: &str,
+) -> Result<(), ConfixError> {
+    let config = load_merged_config(config_paths, overrides, sep)?;
+
+    let mut keys: Vec<&String> = config.keys().collect();
+    keys.sort();
+    for key in keys {
+        let annotated = &config[key];
+        match &annotated.overrides {
+            Some(previous) => println!(
+                "{}={} (from {}, overrides {})",
+                key, annotated.value, annotated.source, previous
+            ),
+            None => println!("{}={} (from {})", key, annotated.value, annotated.source),
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::DEFAULT_SEPARATOR;
     use std::io::Write;
     use tempfile::{Builder, NamedTempFile};
 
@@ -68,11 +278,16 @@ mod tests {
         let file2 = temp_file(r#"{"OVERRIDE": "from_json", "KEY2": "value2"}"#, "json");
 
         let paths = vec![file1.path().to_path_buf(), file2.path().to_path_buf()];
-        let config = merge_configs(&paths).unwrap();
+        let config = merge_configs(&paths, DEFAULT_SEPARATOR).unwrap();
 
-        assert_eq!(config.get("KEY1").unwrap(), "value1");
-        assert_eq!(config.get("KEY2").unwrap(), "value2");
-        assert_eq!(config.get("OVERRIDE").unwrap(), "from_json");
+        assert_eq!(config.get("KEY1").unwrap().value, "value1");
+        assert_eq!(config.get("KEY2").unwrap().value, "value2");
+        assert_eq!(config.get("OVERRIDE").unwrap().value, "from_json");
+        assert_eq!(
+            config.get("OVERRIDE").unwrap().source,
+            ConfigSource::File(file2.path().to_path_buf())
+        );
+        assert!(config.get("OVERRIDE").unwrap().overrides.is_some());
     }
 
     #[test]
@@ -89,14 +304,142 @@ mod tests {
 
         let cmd_args = vec![cmd.to_string(), arg1.to_string(), arg2.to_string()];
 
-        let exit_code = run_command(&paths, &cmd_args).unwrap();
+        let exit_code = run_command(&paths, &[], &cmd_args, DEFAULT_SEPARATOR).unwrap();
         assert_eq!(exit_code, 0);
     }
 
     #[test]
     fn test_run_command_no_cmd() {
         let cmd_args = Vec::<String>::new();
-        let result = run_command(&[], &cmd_args);
+        let result = run_command(&[], &[], &cmd_args, DEFAULT_SEPARATOR);
         assert!(matches!(result, Err(ConfixError::CommandFailed(_))));
     }
+
+    #[test]
+    fn test_set_override_wins_over_files() {
+        let file = temp_file("OVERRIDE=from_file", "env");
+        let paths = vec![file.path().to_path_buf()];
+        let overrides = vec!["OVERRIDE=from_cli".to_string()];
+
+        let config = load_merged_config(&paths, &overrides, DEFAULT_SEPARATOR).unwrap();
+
+        assert_eq!(config.get("OVERRIDE").unwrap().value, "from_cli");
+        assert_eq!(config.get("OVERRIDE").unwrap().source, ConfigSource::Override);
+    }
+
+    #[test]
+    fn test_parse_overrides_invalid() {
+        let overrides = vec!["NOEQUALSIGN".to_string()];
+        let result = parse_overrides(&overrides);
+        assert!(matches!(result, Err(ConfixError::InvalidOverride(_))));
+    }
+
+    // Interpolation tests use JSON fixtures because dotenvy performs its own
+    // (file-scoped) `${VAR}` expansion while parsing `.env` files, which would
+    // otherwise mask whether our post-merge resolver actually ran.
+
+    #[test]
+    fn test_interpolation_across_keys() {
+        let hosts = temp_file("DB_HOST=localhost\nDB_PORT=5432", "env");
+        let url = temp_file(
+            r#"{"DATABASE_URL": "postgres://${DB_HOST}:${DB_PORT}/app"}"#,
+            "json",
+        );
+        let paths = vec![hosts.path().to_path_buf(), url.path().to_path_buf()];
+
+        let config = load_merged_config(&paths, &[], DEFAULT_SEPARATOR).unwrap();
+
+        assert_eq!(
+            config.get("DATABASE_URL").unwrap().value,
+            "postgres://localhost:5432/app"
+        );
+    }
+
+    #[test]
+    fn test_interpolation_falls_back_to_environment() {
+        unsafe {
+            std::env::set_var("CONFIX_TEST_INTERP_ENV", "env_value");
+        }
+        let file = temp_file(r#"{"GREETING": "hello ${CONFIX_TEST_INTERP_ENV}"}"#, "json");
+        let paths = vec![file.path().to_path_buf()];
+
+        let config = load_merged_config(&paths, &[], DEFAULT_SEPARATOR).unwrap();
+
+        assert_eq!(config.get("GREETING").unwrap().value, "hello env_value");
+        unsafe {
+            std::env::remove_var("CONFIX_TEST_INTERP_ENV");
+        }
+    }
+
+    #[test]
+    fn test_interpolation_escape_is_literal() {
+        let file = temp_file(r#"{"LITERAL": "$${NOT_A_REF}"}"#, "json");
+        let paths = vec![file.path().to_path_buf()];
+
+        let config = load_merged_config(&paths, &[], DEFAULT_SEPARATOR).unwrap();
+
+        assert_eq!(config.get("LITERAL").unwrap().value, "${NOT_A_REF}");
+    }
+
+    #[test]
+    fn test_interpolation_cycle_detected() {
+        let file = temp_file(r#"{"A": "${B}", "B": "${A}"}"#, "json");
+        let paths = vec![file.path().to_path_buf()];
+
+        let result = load_merged_config(&paths, &[], DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::InterpolationCycle(_))));
+    }
+
+    #[test]
+    fn test_merge_configs_accepts_inline_json_and_pairs() {
+        let paths = vec![
+            PathBuf::from(r#"{"PORT": "8080"}"#),
+            PathBuf::from("DEBUG=1,MODE=dev"),
+        ];
+
+        let config = merge_configs(&paths, DEFAULT_SEPARATOR).unwrap();
+
+        assert_eq!(config.get("PORT").unwrap().value, "8080");
+        assert_eq!(config.get("DEBUG").unwrap().value, "1");
+        assert_eq!(config.get("MODE").unwrap().value, "dev");
+    }
+
+    #[test]
+    fn test_merge_configs_typo_extension_reports_file_not_found() {
+        let paths = vec![PathBuf::from("confog.toml")];
+        let result = merge_configs(&paths, DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::FileNotFound(_))));
+    }
+
+    #[test]
+    fn test_merge_configs_missing_path_with_separator_reports_file_not_found() {
+        let paths = vec![PathBuf::from("nonexistent/config")];
+        let result = merge_configs(&paths, DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::FileNotFound(_))));
+    }
+
+    #[test]
+    fn test_merge_configs_missing_file_without_separator_is_ambiguous() {
+        let paths = vec![PathBuf::from("not a config at all")];
+        let result = merge_configs(&paths, DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::AmbiguousConfigArg(_))));
+    }
+
+    #[test]
+    fn test_interpolation_unresolved_reference() {
+        let file = temp_file(r#"{"MISSING": "${DOES_NOT_EXIST_ANYWHERE}"}"#, "json");
+        let paths = vec![file.path().to_path_buf()];
+
+        let result = load_merged_config(&paths, &[], DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::UnresolvedReference(_))));
+    }
+
+    #[test]
+    fn test_interpolation_unterminated_reference() {
+        let file = temp_file(r#"{"BROKEN": "prefix ${UNTERMINATED"}"#, "json");
+        let paths = vec![file.path().to_path_buf()];
+
+        let result = load_merged_config(&paths, &[], DEFAULT_SEPARATOR);
+        assert!(matches!(result, Err(ConfixError::UnterminatedInterpolation)));
+    }
 }